@@ -0,0 +1,110 @@
+//! Launch-at-login through `tauri-plugin-autostart`.
+//!
+//! The plugin registers the platform entry (registry key, LaunchAgent, or XDG
+//! autostart file) with [`AUTOSTART_ARG`], so a login launch can be told apart
+//! from a manual one and honor the "start minimized" preference.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_autostart::ManagerExt;
+
+pub const AUTOSTART_ARG: &str = "--autostart";
+const PREFS_FILE: &str = "launch.json";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LaunchAtLogin {
+    pub enabled: bool,
+    pub minimized: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct LaunchPrefs {
+    minimized: bool,
+}
+
+fn prefs_path(app: &AppHandle) -> tauri::Result<PathBuf> {
+    Ok(app.path().app_config_dir()?.join(PREFS_FILE))
+}
+
+fn read_prefs(path: &Path) -> LaunchPrefs {
+    fs::read(path)
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .unwrap_or_default()
+}
+
+fn write_prefs(path: &Path, prefs: LaunchPrefs) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let json = serde_json::to_vec(&prefs).map_err(io::Error::other)?;
+    fs::write(path, json)
+}
+
+/// Hides the main window when the app was started at login with the
+/// "minimized" preference set.
+pub fn apply_launch_mode(app: &AppHandle) -> tauri::Result<()> {
+    if !std::env::args().any(|arg| arg == AUTOSTART_ARG) {
+        return Ok(());
+    }
+    if read_prefs(&prefs_path(app)?).minimized {
+        if let Some(window) = app.get_webview_window("main") {
+            window.hide()?;
+        }
+    }
+    Ok(())
+}
+
+/// Registers or removes the OS login item and stores whether login launches
+/// should start hidden.
+#[tauri::command]
+pub fn set_launch_at_login(app: AppHandle, enabled: bool, minimized: bool) -> Result<(), String> {
+    let autolaunch = app.autolaunch();
+    if enabled {
+        autolaunch.enable()
+    } else {
+        autolaunch.disable()
+    }
+    .map_err(|err| err.to_string())?;
+
+    let path = prefs_path(&app).map_err(|err| err.to_string())?;
+    write_prefs(&path, LaunchPrefs { minimized }).map_err(|err| err.to_string())
+}
+
+/// Reports the current login item state and stored launch preference.
+#[tauri::command]
+pub fn get_launch_at_login(app: AppHandle) -> Result<LaunchAtLogin, String> {
+    let enabled = app
+        .autolaunch()
+        .is_enabled()
+        .map_err(|err| err.to_string())?;
+    let path = prefs_path(&app).map_err(|err| err.to_string())?;
+    Ok(LaunchAtLogin {
+        enabled,
+        minimized: read_prefs(&path).minimized,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefs_round_trip_and_default_when_missing() {
+        let dir = std::env::temp_dir().join(format!("autostart-test-{}", std::process::id()));
+        let path = dir.join(PREFS_FILE);
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(read_prefs(&path), LaunchPrefs::default());
+        write_prefs(&path, LaunchPrefs { minimized: true }).unwrap();
+        assert_eq!(read_prefs(&path), LaunchPrefs { minimized: true });
+
+        fs::write(&path, "not json").unwrap();
+        assert_eq!(read_prefs(&path), LaunchPrefs::default());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
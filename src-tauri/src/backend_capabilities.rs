@@ -0,0 +1,67 @@
+//! Backend version and feature negotiation for the frontend.
+//!
+//! The frontend calls `get_backend_capabilities` on startup and gates UI on
+//! what is reported here instead of invoking commands that may not exist.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+/// Every command registered with the invoke handler.
+pub const COMMANDS: &[&str] = with_commands!(command_names);
+/// Version reported for each command; a command whose arguments or result
+/// change incompatibly should get its own entry instead.
+const COMMAND_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BackendCapabilities {
+    pub backend_version: &'static str,
+    pub schema_version: Option<u32>,
+    pub features: Vec<String>,
+    pub integrations: Vec<String>,
+    pub command_versions: BTreeMap<String, u32>,
+}
+
+/// Reports backend version, schema version, and enabled features.
+#[tauri::command]
+pub fn get_backend_capabilities() -> BackendCapabilities {
+    BackendCapabilities {
+        backend_version: env!("CARGO_PKG_VERSION"),
+        schema_version: None,
+        features: Vec::new(),
+        integrations: Vec::new(),
+        command_versions: COMMANDS
+            .iter()
+            .map(|command| (command.to_string(), COMMAND_VERSION))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_without_schema() {
+        let json = serde_json::to_value(get_backend_capabilities()).unwrap();
+        assert_eq!(json["backend_version"], env!("CARGO_PKG_VERSION"));
+        assert!(json["schema_version"].is_null());
+        assert_eq!(json["features"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn reports_registered_commands() {
+        let capabilities = get_backend_capabilities();
+        let versions = &capabilities.command_versions;
+        assert!(!versions.is_empty());
+        assert_eq!(versions.len(), COMMANDS.len());
+        for command in [
+            "get_backend_capabilities",
+            "expand_rrule",
+            "ollama_search_library",
+            "position_key_between",
+        ] {
+            assert_eq!(versions.get(command), Some(&1), "{command}");
+        }
+    }
+}
@@ -0,0 +1,222 @@
+//! Panic hook that leaves a crash report behind for the next launch.
+//!
+//! Reports are JSON files under `<app data>/crashes`, named by UTC timestamp so
+//! the newest sorts last, and only the last [`MAX_REPORTS`] are kept.
+//! `get_last_crash_report` lets the frontend offer a report dialog on the
+//! following start; `dismiss_crash_report` clears it so it is shown once.
+
+use std::any::Any;
+use std::backtrace::Backtrace;
+use std::fs;
+use std::io;
+use std::panic::{self, Location, PanicHookInfo};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+const CRASH_DIR: &str = "crashes";
+/// Reports kept on disk; older ones are pruned when a new one is written.
+const MAX_REPORTS: usize = 10;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrashReport {
+    pub occurred_at: DateTime<Utc>,
+    pub app_version: String,
+    pub message: String,
+    pub location: Option<String>,
+    pub thread: Option<String>,
+    pub backtrace: String,
+    // Not collected until logging, the database schema, and the sync queue exist.
+    pub log_tail: Option<Vec<String>>,
+    pub schema_version: Option<u32>,
+    pub queue_depth: Option<u64>,
+}
+
+impl CrashReport {
+    fn from_panic(info: &PanicHookInfo<'_>) -> Self {
+        Self::new(info.payload(), info.location())
+    }
+
+    fn new(payload: &(dyn Any + Send), location: Option<&Location<'_>>) -> Self {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|msg| msg.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "non-string panic payload".to_string());
+
+        Self {
+            occurred_at: Utc::now(),
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            message,
+            location: location.map(|loc| loc.to_string()),
+            thread: std::thread::current().name().map(str::to_string),
+            backtrace: Backtrace::force_capture().to_string(),
+            log_tail: None,
+            schema_version: None,
+            queue_depth: None,
+        }
+    }
+}
+
+fn crash_dir(app: &AppHandle) -> tauri::Result<PathBuf> {
+    Ok(app.path().app_data_dir()?.join(CRASH_DIR))
+}
+
+fn write_report(dir: &Path, report: &CrashReport) -> io::Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+    let name = format!(
+        "crash-{}.json",
+        report.occurred_at.format("%Y%m%dT%H%M%S%3fZ")
+    );
+    let path = dir.join(name);
+    let json = serde_json::to_vec_pretty(report).map_err(io::Error::other)?;
+    fs::write(&path, json)?;
+    Ok(path)
+}
+
+/// Report files in `dir`, oldest first.
+fn report_paths(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+
+    let mut paths: Vec<_> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("crash-") && name.ends_with(".json"))
+        })
+        .collect();
+    paths.sort();
+    Ok(paths)
+}
+
+fn prune_reports(dir: &Path, keep: usize) -> io::Result<()> {
+    let paths = report_paths(dir)?;
+    let excess = paths.len().saturating_sub(keep);
+    for path in &paths[..excess] {
+        fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+fn read_latest(dir: &Path) -> io::Result<Option<CrashReport>> {
+    match report_paths(dir)?.pop() {
+        Some(path) => {
+            let report = serde_json::from_slice(&fs::read(path)?).map_err(io::Error::other)?;
+            Ok(Some(report))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Installs a panic hook that writes a crash report before deferring to the
+/// default hook.
+pub fn install_panic_hook(app: &AppHandle) -> tauri::Result<()> {
+    let dir = crash_dir(app)?;
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let report = CrashReport::from_panic(info);
+        let written = write_report(&dir, &report).and_then(|_| prune_reports(&dir, MAX_REPORTS));
+        if let Err(err) = written {
+            eprintln!("failed to write crash report: {err}");
+        }
+        previous(info);
+    }));
+    Ok(())
+}
+
+/// Returns the most recent crash report that has not been dismissed, if any.
+#[tauri::command]
+pub fn get_last_crash_report(app: AppHandle) -> Result<Option<CrashReport>, String> {
+    let dir = crash_dir(&app).map_err(|err| err.to_string())?;
+    read_latest(&dir).map_err(|err| err.to_string())
+}
+
+/// Deletes stored crash reports once the user has seen or sent them.
+#[tauri::command]
+pub fn dismiss_crash_report(app: AppHandle) -> Result<(), String> {
+    let dir = crash_dir(&app).map_err(|err| err.to_string())?;
+    prune_reports(&dir, 0).map_err(|err| err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("crash-test-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn report(second: u32, message: &str) -> CrashReport {
+        CrashReport {
+            occurred_at: Utc.with_ymd_and_hms(2026, 10, 16, 9, 0, second).unwrap(),
+            app_version: "0.1.0".to_string(),
+            message: message.to_string(),
+            location: Some("src/main.rs:1:1".to_string()),
+            thread: Some("main".to_string()),
+            backtrace: String::new(),
+            log_tail: None,
+            schema_version: None,
+            queue_depth: None,
+        }
+    }
+
+    #[test]
+    fn missing_dir_has_no_report() {
+        let dir = temp_dir("missing");
+        assert_eq!(read_latest(&dir).unwrap(), None);
+    }
+
+    #[test]
+    fn latest_report_wins() {
+        let dir = temp_dir("latest");
+        write_report(&dir, &report(5, "newer")).unwrap();
+        write_report(&dir, &report(1, "older")).unwrap();
+        fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+        let latest = read_latest(&dir).unwrap().unwrap();
+        assert_eq!(latest, report(5, "newer"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn prunes_oldest_and_dismisses_all() {
+        let dir = temp_dir("prune");
+        for second in 0..5 {
+            write_report(&dir, &report(second, "crash")).unwrap();
+        }
+        prune_reports(&dir, 3).unwrap();
+        let paths = report_paths(&dir).unwrap();
+        assert_eq!(paths.len(), 3);
+        assert!(paths[0].ends_with("crash-20261016T090002000Z.json"));
+
+        prune_reports(&dir, 0).unwrap();
+        assert_eq!(read_latest(&dir).unwrap(), None);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn captures_panic_payload_and_location() {
+        let report = CrashReport::new(&"boom", Some(Location::caller()));
+        assert_eq!(report.message, "boom");
+        assert!(report.location.unwrap().contains("crash.rs"));
+        assert_eq!(report.queue_depth, None);
+
+        let report = CrashReport::new(&format!("boom {}", 42), None);
+        assert_eq!(report.message, "boom 42");
+        assert_eq!(report.location, None);
+
+        let report = CrashReport::new(&42_u32, None);
+        assert_eq!(report.message, "non-string panic payload");
+    }
+}
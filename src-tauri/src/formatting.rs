@@ -0,0 +1,272 @@
+//! Locale-aware date and relative-time formatting for backend-generated text.
+//!
+//! Dates use chrono's `unstable-locales` data (glibc locale names such as
+//! `de_DE`). Relative phrases are not covered by that data, so they come from a
+//! small per-language table. Both commands fall back to English for locales
+//! they do not know rather than failing.
+
+use chrono::{DateTime, FixedOffset, Locale, Utc};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DateStyle {
+    #[default]
+    Date,
+    Time,
+    DateTime,
+}
+
+impl DateStyle {
+    fn pattern(self) -> &'static str {
+        match self {
+            Self::Date => "%x",
+            Self::Time => "%X",
+            Self::DateTime => "%c",
+        }
+    }
+}
+
+/// Locale used for a bare language tag, where the region is not simply the
+/// language code upper-cased.
+#[rustfmt::skip]
+const DEFAULT_REGIONS: &[(&str, &str)] = &[
+    ("ar", "ar_EG"), ("ca", "ca_ES"), ("cs", "cs_CZ"), ("da", "da_DK"),
+    ("el", "el_GR"), ("en", "en_US"), ("et", "et_EE"), ("fa", "fa_IR"),
+    ("he", "he_IL"), ("hi", "hi_IN"), ("ja", "ja_JP"), ("ko", "ko_KR"),
+    ("nb", "nb_NO"), ("nn", "nn_NO"), ("no", "nb_NO"), ("pt", "pt_BR"),
+    ("sl", "sl_SI"), ("sv", "sv_SE"), ("uk", "uk_UA"), ("vi", "vi_VN"),
+    ("zh", "zh_CN"),
+];
+
+/// Maps BCP 47 tags (`de-DE`, `ja`, `zh-Hans-CN`) onto chrono locale names,
+/// dropping script subtags and falling back to `en_US`.
+fn resolve_locale(tag: &str) -> Locale {
+    let mut subtags = tag.trim().split(['-', '_']);
+    let language = subtags.next().unwrap_or_default().to_ascii_lowercase();
+    let region = subtags
+        .find(|subtag| subtag.len() != 4)
+        .filter(|subtag| subtag.len() == 2 || subtag.len() == 3)
+        .map(str::to_ascii_uppercase);
+
+    let default = DEFAULT_REGIONS
+        .iter()
+        .find(|(lang, _)| *lang == language)
+        .map_or_else(
+            || format!("{language}_{}", language.to_ascii_uppercase()),
+            |(_, locale)| locale.to_string(),
+        );
+    region
+        .map(|region| format!("{language}_{region}"))
+        .into_iter()
+        .chain([default])
+        .find_map(|name| Locale::try_from(name.as_str()).ok())
+        .unwrap_or(Locale::en_US)
+}
+
+/// Formats a timestamp in the given locale, keeping the caller's UTC offset.
+#[tauri::command]
+pub fn format_date(
+    value: DateTime<FixedOffset>,
+    locale: String,
+    style: Option<DateStyle>,
+) -> String {
+    value
+        .format_localized(style.unwrap_or_default().pattern(), resolve_locale(&locale))
+        .to_string()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    Minute,
+    Hour,
+    Day,
+    Month,
+    Year,
+}
+
+struct Phrases {
+    now: &'static str,
+    future: &'static str,
+    past: &'static str,
+    // (singular, plural) per unit, in `Unit` order.
+    units: [(&'static str, &'static str); 5],
+}
+
+const EN: Phrases = Phrases {
+    now: "just now",
+    future: "in {n} {unit}",
+    past: "{n} {unit} ago",
+    units: [
+        ("minute", "minutes"),
+        ("hour", "hours"),
+        ("day", "days"),
+        ("month", "months"),
+        ("year", "years"),
+    ],
+};
+
+const DE: Phrases = Phrases {
+    now: "gerade eben",
+    future: "in {n} {unit}",
+    past: "vor {n} {unit}",
+    units: [
+        ("Minute", "Minuten"),
+        ("Stunde", "Stunden"),
+        ("Tag", "Tagen"),
+        ("Monat", "Monaten"),
+        ("Jahr", "Jahren"),
+    ],
+};
+
+const FR: Phrases = Phrases {
+    now: "à l’instant",
+    future: "dans {n} {unit}",
+    past: "il y a {n} {unit}",
+    units: [
+        ("minute", "minutes"),
+        ("heure", "heures"),
+        ("jour", "jours"),
+        ("mois", "mois"),
+        ("an", "ans"),
+    ],
+};
+
+const ES: Phrases = Phrases {
+    now: "ahora mismo",
+    future: "dentro de {n} {unit}",
+    past: "hace {n} {unit}",
+    units: [
+        ("minuto", "minutos"),
+        ("hora", "horas"),
+        ("día", "días"),
+        ("mes", "meses"),
+        ("año", "años"),
+    ],
+};
+
+fn phrases_for(locale: &str) -> &'static Phrases {
+    let language = locale
+        .trim()
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    match language.as_str() {
+        "de" => &DE,
+        "fr" => &FR,
+        "es" => &ES,
+        _ => &EN,
+    }
+}
+
+fn relative_phrase(seconds: i64, locale: &str) -> String {
+    const MINUTE: i64 = 60;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+    const MONTH: i64 = 30 * DAY;
+    const YEAR: i64 = 365 * DAY;
+
+    let phrases = phrases_for(locale);
+    let magnitude = seconds.abs();
+    if magnitude < 45 {
+        return phrases.now.to_string();
+    }
+
+    let (unit, size) = match magnitude {
+        m if m < 45 * MINUTE => (Unit::Minute, MINUTE),
+        m if m < 22 * HOUR => (Unit::Hour, HOUR),
+        m if m < 26 * DAY => (Unit::Day, DAY),
+        m if m < 320 * DAY => (Unit::Month, MONTH),
+        _ => (Unit::Year, YEAR),
+    };
+    let count = ((magnitude + size / 2) / size).max(1);
+    let (singular, plural) = phrases.units[unit as usize];
+    let template = if seconds > 0 {
+        phrases.future
+    } else {
+        phrases.past
+    };
+    template
+        .replace("{n}", &count.to_string())
+        .replace("{unit}", if count == 1 { singular } else { plural })
+}
+
+/// Describes `value` relative to `now` (defaults to the current time), e.g.
+/// "in 3 days" or "vor 2 Stunden".
+#[tauri::command]
+pub fn format_relative_time(
+    value: DateTime<FixedOffset>,
+    locale: String,
+    now: Option<DateTime<FixedOffset>>,
+) -> String {
+    let now = now.map_or_else(Utc::now, |now| now.with_timezone(&Utc));
+    relative_phrase((value.with_timezone(&Utc) - now).num_seconds(), &locale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(value: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(value).unwrap()
+    }
+
+    fn relative(value: &str, locale: &str) -> String {
+        format_relative_time(
+            at(value),
+            locale.to_string(),
+            Some(at("2025-10-08T12:00:00Z")),
+        )
+    }
+
+    #[test]
+    fn resolves_bcp47_tags() {
+        assert_eq!(resolve_locale("de-AT"), Locale::de_AT);
+        assert_eq!(resolve_locale("fr"), Locale::fr_FR);
+        assert_eq!(resolve_locale("ja"), Locale::ja_JP);
+        assert_eq!(resolve_locale("sv"), Locale::sv_SE);
+        assert_eq!(resolve_locale("zh-Hans-CN"), Locale::zh_CN);
+        assert_eq!(resolve_locale("zh-Hant-TW"), Locale::zh_TW);
+        assert_eq!(resolve_locale("en-XX"), Locale::en_US);
+        assert_eq!(resolve_locale("xx-YY"), Locale::en_US);
+    }
+
+    #[test]
+    fn formats_dates_per_locale() {
+        let value = at("2025-10-08T09:30:00+02:00");
+        assert_eq!(format_date(value, "en-US".to_string(), None), "10/08/2025");
+        assert_eq!(
+            format_date(value, "de".to_string(), Some(DateStyle::Date)),
+            "08.10.2025"
+        );
+        assert!(format_date(value, "de_DE".to_string(), Some(DateStyle::DateTime)).contains("Okt"));
+        assert_eq!(
+            format_date(value, "xx-YY".to_string(), None),
+            format_date(value, "en-US".to_string(), None)
+        );
+    }
+
+    #[test]
+    fn relative_time_in_english() {
+        assert_eq!(relative("2025-10-08T12:00:30Z", "en-US"), "just now");
+        assert_eq!(relative("2025-10-08T12:01:00Z", "en"), "in 1 minute");
+        assert_eq!(relative("2025-10-08T10:00:00Z", "en"), "2 hours ago");
+        assert_eq!(relative("2025-10-11T12:00:00Z", "en"), "in 3 days");
+        assert_eq!(relative("2025-08-08T12:00:00Z", "en"), "2 months ago");
+        assert_eq!(relative("2027-10-08T12:00:00Z", "en"), "in 2 years");
+    }
+
+    #[test]
+    fn relative_time_in_other_languages() {
+        assert_eq!(relative("2025-10-08T10:00:00Z", "de-DE"), "vor 2 Stunden");
+        assert_eq!(relative("2025-10-09T12:00:00Z", "de"), "in 1 Tag");
+        assert_eq!(relative("2025-10-05T12:00:00Z", "fr_FR"), "il y a 3 jours");
+        assert_eq!(relative("2026-10-08T12:00:00Z", "es"), "dentro de 1 año");
+    }
+
+    #[test]
+    fn unknown_language_falls_back_to_english() {
+        assert_eq!(relative("2025-10-08T13:00:00Z", "ja-JP"), "in 1 hour");
+    }
+}
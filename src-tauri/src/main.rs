@@ -1,11 +1,66 @@
 //! Tauri main entry
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+use tauri::Manager;
+use tauri_plugin_autostart::MacosLauncher;
+
+/// Expands `$callback![...]` over every command exposed to the frontend, so the
+/// invoke handler and [`backend_capabilities::COMMANDS`] share one list.
+macro_rules! with_commands {
+    ($($callback:tt)+) => {
+        $($callback)+![
+            autostart::set_launch_at_login,
+            autostart::get_launch_at_login,
+            backend_capabilities::get_backend_capabilities,
+            crash::get_last_crash_report,
+            crash::dismiss_crash_report,
+            formatting::format_date,
+            formatting::format_relative_time,
+            model_capabilities::get_model_capabilities,
+            ollama_library::ollama_search_library,
+            ordering::position_key_between,
+            ordering::rebalance_positions,
+            recurrence::expand_rrule,
+            system_capabilities::get_system_ai_capabilities,
+            time_block::parse_time_block,
+            time_block::format_time_block,
+        ]
+    };
+}
+
+macro_rules! command_names {
+    ($($module:ident::$command:ident),* $(,)?) => {
+        &[$(stringify!($command)),*]
+    };
+}
+
+mod autostart;
+mod backend_capabilities;
+mod crash;
+mod formatting;
+mod model_capabilities;
+mod ollama_library;
+mod ordering;
+mod recurrence;
+mod system_capabilities;
+mod time_block;
+
 fn main() {
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
+        .plugin(tauri_plugin_autostart::init(
+            MacosLauncher::LaunchAgent,
+            Some(vec![autostart::AUTOSTART_ARG]),
+        ))
+        .setup(|app| {
+            app.manage(ollama_library::LibraryCache::new()?);
+            crash::install_panic_hook(app.handle())?;
+            autostart::apply_launch_mode(app.handle())?;
+            Ok(())
+        })
+        .invoke_handler(with_commands!(tauri::generate_handler))
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
-}
\ No newline at end of file
+}
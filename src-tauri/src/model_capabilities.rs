@@ -0,0 +1,122 @@
+//! Static model capability registry.
+//!
+//! Seeded with the cloud models offered in Settings so requests can be checked
+//! for vision, tool, JSON-mode, and context-size support before dispatch.
+//! Local (Ollama) models are discovered at runtime and are not listed here.
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ModelCapabilities {
+    pub provider: &'static str,
+    pub model: &'static str,
+    pub vision: bool,
+    pub tools: bool,
+    pub json_mode: bool,
+    pub context_window: u32,
+    pub max_output_tokens: Option<u32>,
+}
+
+const fn entry(
+    provider: &'static str,
+    model: &'static str,
+    (vision, tools, json_mode): (bool, bool, bool),
+    context_window: u32,
+    max_output_tokens: Option<u32>,
+) -> ModelCapabilities {
+    ModelCapabilities {
+        provider,
+        model,
+        vision,
+        tools,
+        json_mode,
+        context_window,
+        max_output_tokens,
+    }
+}
+
+// (vision, tools, json_mode)
+#[rustfmt::skip]
+const REGISTRY: &[ModelCapabilities] = &[
+    entry("openai", "gpt-4o", (true, true, true), 128_000, Some(16_384)),
+    entry("openai", "gpt-4o-mini", (true, true, true), 128_000, Some(16_384)),
+    entry("openai", "o3-mini", (false, true, true), 200_000, Some(100_000)),
+    entry("anthropic", "claude-3.5-sonnet", (true, true, false), 200_000, Some(8_192)),
+    entry("anthropic", "claude-3.5-haiku", (false, true, false), 200_000, Some(8_192)),
+    entry("anthropic", "claude-3-opus", (true, true, false), 200_000, Some(4_096)),
+    entry("openrouter", "anthropic/claude-3.5-sonnet", (true, true, false), 200_000, Some(8_192)),
+    entry("openrouter", "meta-llama/llama-3.1-405b", (false, false, false), 131_072, None),
+    entry("deepseek", "deepseek-chat", (false, true, true), 64_000, Some(8_192)),
+    entry("deepseek", "deepseek-coder", (false, true, true), 64_000, Some(8_192)),
+    entry("mistral", "mistral-large-latest", (false, true, true), 131_072, None),
+    entry("mistral", "mistral-medium", (false, true, true), 32_000, None),
+    entry("mistral", "codestral", (false, true, true), 256_000, None),
+    entry("gemini", "gemini-1.5-pro", (true, true, true), 2_097_152, Some(8_192)),
+    entry("gemini", "gemini-1.5-flash", (true, true, true), 1_048_576, Some(8_192)),
+];
+
+fn lookup(provider: &str, model: &str) -> Option<ModelCapabilities> {
+    let provider = provider.trim();
+    let model = model.trim();
+    REGISTRY
+        .iter()
+        .find(|caps| {
+            caps.provider.eq_ignore_ascii_case(provider) && caps.model.eq_ignore_ascii_case(model)
+        })
+        .copied()
+}
+
+/// Returns the known capabilities for a provider/model pair, or `None` when
+/// the model is not in the registry.
+#[tauri::command]
+pub fn get_model_capabilities(provider: String, model: String) -> Option<ModelCapabilities> {
+    lookup(&provider, &model)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn finds_seeded_models() {
+        let caps = lookup("openai", "gpt-4o").unwrap();
+        assert!(caps.vision && caps.tools && caps.json_mode);
+        assert_eq!(caps.context_window, 128_000);
+
+        let caps = lookup("openai", "o3-mini").unwrap();
+        assert!(!caps.vision);
+    }
+
+    #[test]
+    fn lookup_ignores_case_and_whitespace() {
+        assert_eq!(
+            lookup(" Gemini ", "GEMINI-1.5-FLASH"),
+            lookup("gemini", "gemini-1.5-flash")
+        );
+        assert!(lookup("gemini", "gemini-1.5-flash").is_some());
+    }
+
+    #[test]
+    fn unknown_models_are_none() {
+        assert_eq!(lookup("openai", "auto"), None);
+        assert_eq!(lookup("local", "llama3.1:8b"), None);
+        assert_eq!(lookup("anthropic", "gpt-4o"), None);
+    }
+
+    #[test]
+    fn registry_has_no_duplicates() {
+        let mut seen = HashSet::new();
+        for caps in REGISTRY {
+            assert!(
+                seen.insert((caps.provider, caps.model)),
+                "duplicate entry for {}/{}",
+                caps.provider,
+                caps.model
+            );
+            assert!(caps
+                .max_output_tokens
+                .is_none_or(|max| max <= caps.context_window));
+        }
+    }
+}
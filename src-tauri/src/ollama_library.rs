@@ -0,0 +1,240 @@
+//! Ollama model library search.
+//!
+//! ollama.com has no public JSON index, so this reads the library search page
+//! and pulls fields from the `x-test-*` attributes on each model card. Results
+//! are cached per query for an hour, for at most [`MAX_CACHED_QUERIES`]
+//! queries.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tauri::State;
+
+const SEARCH_URL: &str = "https://ollama.com/search";
+const CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+const MAX_CACHED_QUERIES: usize = 64;
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct LibraryModel {
+    pub name: String,
+    pub description: String,
+    pub sizes: Vec<String>,
+    pub capabilities: Vec<String>,
+    pub pulls: Option<String>,
+    pub tag_count: Option<u32>,
+    pub updated: Option<String>,
+}
+
+pub struct LibraryCache {
+    client: reqwest::Client,
+    entries: Mutex<HashMap<String, (Instant, Vec<LibraryModel>)>>,
+}
+
+impl LibraryCache {
+    pub fn new() -> reqwest::Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .connect_timeout(CONNECT_TIMEOUT)
+            .user_agent(concat!("LibreOllama/", env!("CARGO_PKG_VERSION")))
+            .build()?;
+        Ok(Self {
+            client,
+            entries: Mutex::default(),
+        })
+    }
+
+    fn get(&self, key: &str) -> Option<Vec<LibraryModel>> {
+        let entries = self.entries.lock().unwrap();
+        let (fetched_at, models) = entries.get(key)?;
+        (fetched_at.elapsed() < CACHE_TTL).then(|| models.clone())
+    }
+
+    /// Stores a result, dropping expired entries and then the oldest ones so
+    /// the cache never holds more than [`MAX_CACHED_QUERIES`] queries.
+    fn insert(&self, key: String, fetched_at: Instant, models: Vec<LibraryModel>) {
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|_, (at, _)| at.elapsed() < CACHE_TTL);
+        entries.remove(&key);
+        while entries.len() >= MAX_CACHED_QUERIES {
+            let Some(oldest) = entries
+                .iter()
+                .min_by_key(|(_, (at, _))| *at)
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+            entries.remove(&oldest);
+        }
+        entries.insert(key, (fetched_at, models));
+    }
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
+
+fn clean_text(text: &str) -> String {
+    decode_entities(text)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Text content directly inside each element carrying `attr`.
+fn attr_texts(html: &str, attr: &str) -> Vec<String> {
+    let mut texts = Vec::new();
+    let mut rest = html;
+    while let Some(pos) = rest.find(attr) {
+        let after = &rest[pos + attr.len()..];
+        rest = after;
+        let bounded = after.starts_with(['>', ' ', '\n', '\t', '=', '/']);
+        let Some(open_end) = after.find('>').filter(|_| bounded) else {
+            continue;
+        };
+        let content = &after[open_end + 1..];
+        let text = &content[..content.find('<').unwrap_or(content.len())];
+        texts.push(clean_text(text));
+    }
+    texts
+}
+
+fn first_paragraph(html: &str) -> Option<String> {
+    let start = html.find("<p")?;
+    let open_end = start + html[start..].find('>')? + 1;
+    let close = open_end + html[open_end..].find("</p>")?;
+    let mut text = String::new();
+    let mut in_tag = false;
+    for ch in html[open_end..close].chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+    Some(clean_text(&text))
+}
+
+fn parse_search_page(html: &str) -> Vec<LibraryModel> {
+    html.split("x-test-model")
+        .skip(1)
+        .filter_map(|card| {
+            let name = attr_texts(card, "x-test-search-response-title")
+                .into_iter()
+                .next()
+                .filter(|name| !name.is_empty())?;
+            Some(LibraryModel {
+                name,
+                description: first_paragraph(card).unwrap_or_default(),
+                sizes: attr_texts(card, "x-test-size"),
+                capabilities: attr_texts(card, "x-test-capability"),
+                pulls: attr_texts(card, "x-test-pull-count").into_iter().next(),
+                tag_count: attr_texts(card, "x-test-tag-count")
+                    .first()
+                    .and_then(|count| count.replace(',', "").parse().ok()),
+                updated: attr_texts(card, "x-test-updated").into_iter().next(),
+            })
+        })
+        .collect()
+}
+
+/// Searches the public Ollama model library, serving repeat queries from the
+/// in-memory cache.
+#[tauri::command]
+pub async fn ollama_search_library(
+    query: String,
+    cache: State<'_, LibraryCache>,
+) -> Result<Vec<LibraryModel>, String> {
+    let key = query.trim().to_lowercase();
+    if let Some(models) = cache.get(&key) {
+        return Ok(models);
+    }
+
+    let html = cache
+        .client
+        .get(SEARCH_URL)
+        .query(&[("q", key.as_str())])
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|err| err.to_string())?
+        .text()
+        .await
+        .map_err(|err| err.to_string())?;
+
+    let models = parse_search_page(&html);
+    cache.insert(key, Instant::now(), models.clone());
+    Ok(models)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../tests/fixtures/ollama_search.html");
+
+    #[test]
+    fn parses_model_cards() {
+        let models = parse_search_page(FIXTURE);
+        assert_eq!(models.len(), 3);
+
+        assert_eq!(
+            models[0],
+            LibraryModel {
+                name: "llama3.1".to_string(),
+                description: "Llama 3.1 is a new state-of-the-art model from Meta available in 8B, 70B and 405B parameter sizes.".to_string(),
+                sizes: vec!["8b".to_string(), "70b".to_string(), "405b".to_string()],
+                capabilities: vec!["tools".to_string()],
+                pulls: Some("108.5M".to_string()),
+                tag_count: Some(93),
+                updated: Some("10 months ago".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn decodes_entities_and_handles_missing_sizes() {
+        let models = parse_search_page(FIXTURE);
+        assert!(models[1].description.contains("visual & language"));
+        assert_eq!(models[1].capabilities, ["vision"]);
+
+        assert_eq!(models[2].name, "nomic-embed-text");
+        assert!(models[2].sizes.is_empty());
+        assert_eq!(models[2].tag_count, Some(3));
+    }
+
+    #[test]
+    fn empty_page_yields_no_models() {
+        assert!(parse_search_page("<html><body>No models found</body></html>").is_empty());
+    }
+
+    #[test]
+    fn cache_evicts_expired_and_oldest_entries() {
+        let cache = LibraryCache::new().unwrap();
+        let now = Instant::now();
+        if let Some(expired) = now.checked_sub(CACHE_TTL) {
+            cache.insert("stale".to_string(), expired, Vec::new());
+            assert_eq!(cache.get("stale"), None);
+        }
+        for i in 0..MAX_CACHED_QUERIES + 5 {
+            let fetched_at = now + Duration::from_millis(i as u64);
+            cache.insert(format!("query {i}"), fetched_at, Vec::new());
+        }
+
+        let entries = cache.entries.lock().unwrap();
+        assert_eq!(entries.len(), MAX_CACHED_QUERIES);
+        assert!(!entries.contains_key("stale"));
+        assert!(!entries.contains_key("query 0"));
+        assert!(entries.contains_key(&format!("query {}", MAX_CACHED_QUERIES + 4)));
+    }
+}
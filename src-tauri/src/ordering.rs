@@ -0,0 +1,249 @@
+//! Fractional-index position keys for manual ordering.
+//!
+//! A key is a base-62 fraction written with the digits `0-9A-Za-z`, so plain
+//! byte comparison (never locale collation) orders keys the same way their
+//! values compare. Keys never end in `0`, which guarantees there is always
+//! room for another key between any two. Repeated inserts at the same spot
+//! make keys grow; once one passes [`MAX_KEY_LEN`] the list should be
+//! reassigned with [`rebalance`].
+
+use std::fmt;
+
+use serde::Serialize;
+
+const DIGITS: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+const BASE: u128 = DIGITS.len() as u128;
+/// Keys longer than this should trigger a rebalance of their list.
+pub const MAX_KEY_LEN: usize = 32;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrderingError {
+    InvalidKey(String),
+    OutOfOrder { before: String, after: String },
+}
+
+impl fmt::Display for OrderingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidKey(key) => write!(f, "invalid position key: {key:?}"),
+            Self::OutOfOrder { before, after } => {
+                write!(f, "position key {before:?} must sort before {after:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for OrderingError {}
+
+fn digit_value(byte: u8) -> usize {
+    DIGITS.iter().position(|d| *d == byte).unwrap_or(0)
+}
+
+fn validate(key: &str) -> Result<(), OrderingError> {
+    let valid =
+        !key.is_empty() && key.bytes().all(|b| b.is_ascii_alphanumeric()) && !key.ends_with('0');
+    if valid {
+        Ok(())
+    } else {
+        Err(OrderingError::InvalidKey(key.to_string()))
+    }
+}
+
+/// Midpoint of two fractions, where `a` may be empty (zero) and `b` of
+/// `None` means one.
+fn midpoint(a: &[u8], b: Option<&[u8]>) -> Vec<u8> {
+    if let Some(b) = b {
+        let common = b
+            .iter()
+            .enumerate()
+            .take_while(|(i, digit)| a.get(*i).copied().unwrap_or(DIGITS[0]) == **digit)
+            .count();
+        if common > 0 {
+            let mut key = b[..common].to_vec();
+            key.extend(midpoint(
+                a.get(common..).unwrap_or_default(),
+                Some(&b[common..]),
+            ));
+            return key;
+        }
+    }
+
+    let digit_a = a.first().map_or(0, |d| digit_value(*d));
+    let digit_b = b.map_or(DIGITS.len(), |b| digit_value(b[0]));
+    if digit_b - digit_a > 1 {
+        vec![DIGITS[(digit_a + digit_b).div_ceil(2)]]
+    } else if let Some(b) = b.filter(|b| b.len() > 1) {
+        vec![b[0]]
+    } else {
+        let mut key = vec![DIGITS[digit_a]];
+        key.extend(midpoint(a.get(1..).unwrap_or_default(), None));
+        key
+    }
+}
+
+/// Generates a key sorting strictly between `before` and `after`; `None`
+/// means the start or end of the list.
+pub fn key_between(before: Option<&str>, after: Option<&str>) -> Result<String, OrderingError> {
+    for key in [before, after].into_iter().flatten() {
+        validate(key)?;
+    }
+    if let (Some(before), Some(after)) = (before, after) {
+        if before >= after {
+            return Err(OrderingError::OutOfOrder {
+                before: before.to_string(),
+                after: after.to_string(),
+            });
+        }
+    }
+    let key = midpoint(
+        before.unwrap_or_default().as_bytes(),
+        after.map(str::as_bytes),
+    );
+    Ok(String::from_utf8(key).expect("position keys are ASCII"))
+}
+
+/// Evenly spaced fresh keys for `count` items, using the fewest digits that
+/// leave a gap between neighbors.
+pub fn rebalance(count: usize) -> Vec<String> {
+    let slots = count as u128 + 1;
+    let mut width = 1;
+    let mut span = BASE;
+    while span <= slots && width < MAX_KEY_LEN / 2 {
+        width += 1;
+        span *= BASE;
+    }
+
+    (1..=count as u128)
+        .map(|i| {
+            let mut value = i * span / slots;
+            let mut digits = vec![DIGITS[0]; width];
+            for digit in digits.iter_mut().rev() {
+                *digit = DIGITS[(value % BASE) as usize];
+                value /= BASE;
+            }
+            while digits.last() == Some(&DIGITS[0]) {
+                digits.pop();
+            }
+            String::from_utf8(digits).expect("position keys are ASCII")
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PositionKey {
+    pub key: String,
+    pub needs_rebalance: bool,
+}
+
+/// Returns a position key between two neighbors for a reorder.
+#[tauri::command]
+pub fn position_key_between(
+    before: Option<String>,
+    after: Option<String>,
+) -> Result<PositionKey, String> {
+    let key = key_between(before.as_deref(), after.as_deref()).map_err(|err| err.to_string())?;
+    Ok(PositionKey {
+        needs_rebalance: key.len() > MAX_KEY_LEN,
+        key,
+    })
+}
+
+/// Returns `count` fresh, evenly spaced position keys for a whole list.
+#[tauri::command]
+pub fn rebalance_positions(count: usize) -> Vec<String> {
+    rebalance(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn between(before: Option<&str>, after: Option<&str>) -> String {
+        let key = key_between(before, after).unwrap();
+        assert!(validate(&key).is_ok());
+        if let Some(before) = before {
+            assert!(before < key.as_str(), "{before} < {key}");
+        }
+        if let Some(after) = after {
+            assert!(key.as_str() < after, "{key} < {after}");
+        }
+        key
+    }
+
+    #[test]
+    fn generates_keys_between_neighbors() {
+        assert_eq!(between(Some("1"), Some("3")), "2");
+        between(Some("V"), Some("W"));
+        between(Some("Vz"), Some("W"));
+        between(Some("a0001"), Some("a0002"));
+        between(Some("a"), Some("a1"));
+    }
+
+    #[test]
+    fn generates_keys_at_the_ends() {
+        let first = between(None, None);
+        let mut head = first.clone();
+        let mut tail = first;
+        for _ in 0..100 {
+            head = between(None, Some(&head));
+            tail = between(Some(&tail), None);
+        }
+        assert!(head < tail);
+    }
+
+    #[test]
+    fn rejects_invalid_or_unordered_keys() {
+        assert!(matches!(
+            key_between(Some("a0"), None),
+            Err(OrderingError::InvalidKey(_))
+        ));
+        assert!(matches!(
+            key_between(Some(""), None),
+            Err(OrderingError::InvalidKey(_))
+        ));
+        assert!(matches!(
+            key_between(Some("a-b"), None),
+            Err(OrderingError::InvalidKey(_))
+        ));
+        assert!(matches!(
+            key_between(Some("b"), Some("a")),
+            Err(OrderingError::OutOfOrder { .. })
+        ));
+        assert!(key_between(Some("a"), Some("a")).is_err());
+    }
+
+    #[test]
+    fn long_keys_trigger_rebalance() {
+        let low = "V".to_string();
+        let mut high = between(Some(&low), None);
+        let mut needs_rebalance = false;
+        for _ in 0..500 {
+            let next = position_key_between(Some(low.clone()), Some(high.clone())).unwrap();
+            needs_rebalance |= next.needs_rebalance;
+            high = next.key;
+        }
+        assert!(needs_rebalance);
+        assert!(high.len() > MAX_KEY_LEN);
+
+        let keys = rebalance(3);
+        assert!(keys.iter().all(|key| key.len() <= 2));
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn rebalanced_keys_are_sorted_short_and_valid() {
+        for count in [0, 1, 10, 61, 62, 5_000] {
+            let keys = rebalance(count);
+            assert_eq!(keys.len(), count);
+            assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+            for key in &keys {
+                assert!(validate(key).is_ok(), "{key}");
+                assert!(key.len() <= 3);
+            }
+        }
+        let keys = rebalance(2);
+        between(Some(&keys[0]), Some(&keys[1]));
+        between(None, Some(&keys[0]));
+        between(Some(&keys[1]), None);
+    }
+}
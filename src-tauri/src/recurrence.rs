@@ -0,0 +1,539 @@
+//! RFC 5545 RRULE parsing and bounded expansion for task recurrence.
+//!
+//! Tasks are date-based, so rules expand to calendar dates and any time part
+//! of `UNTIL` is ignored. Supported parts: FREQ (DAILY/WEEKLY/MONTHLY/YEARLY),
+//! INTERVAL, COUNT, UNTIL, BYDAY (with ordinals for MONTHLY/YEARLY),
+//! BYMONTHDAY, BYMONTH, and WKST. Anything else is rejected rather than
+//! silently ignored so imported rules never expand differently than intended.
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+/// Hard cap on occurrences returned by a single expansion.
+pub const MAX_OCCURRENCES: usize = 1_000;
+/// Hard cap on periods scanned, so rules that never match still terminate.
+const MAX_PERIODS: i64 = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByDay {
+    pub ordinal: Option<i32>,
+    pub weekday: Weekday,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecurrenceRule {
+    pub freq: Frequency,
+    pub interval: u32,
+    pub count: Option<u32>,
+    pub until: Option<NaiveDate>,
+    pub by_day: Vec<ByDay>,
+    pub by_month_day: Vec<i32>,
+    pub by_month: Vec<u32>,
+    pub week_start: Weekday,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecurrenceError {
+    MissingFrequency,
+    Invalid(String),
+    Unsupported(String),
+    CountWithUntil,
+}
+
+impl fmt::Display for RecurrenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingFrequency => f.write_str("RRULE is missing FREQ"),
+            Self::Invalid(part) => write!(f, "invalid RRULE part: {part}"),
+            Self::Unsupported(part) => write!(f, "unsupported RRULE part: {part}"),
+            Self::CountWithUntil => f.write_str("RRULE cannot combine COUNT and UNTIL"),
+        }
+    }
+}
+
+impl std::error::Error for RecurrenceError {}
+
+fn parse_weekday(code: &str) -> Option<Weekday> {
+    match code {
+        "MO" => Some(Weekday::Mon),
+        "TU" => Some(Weekday::Tue),
+        "WE" => Some(Weekday::Wed),
+        "TH" => Some(Weekday::Thu),
+        "FR" => Some(Weekday::Fri),
+        "SA" => Some(Weekday::Sat),
+        "SU" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+fn parse_by_day(value: &str) -> Option<ByDay> {
+    let split = value.len().checked_sub(2)?;
+    let weekday = parse_weekday(value.get(split..)?)?;
+    let ordinal = match &value[..split] {
+        "" => None,
+        digits => {
+            let ordinal: i32 = digits.parse().ok()?;
+            if ordinal == 0 || !(-53..=53).contains(&ordinal) {
+                return None;
+            }
+            Some(ordinal)
+        }
+    };
+    Some(ByDay { ordinal, weekday })
+}
+
+fn parse_until(value: &str) -> Option<NaiveDate> {
+    let date = NaiveDate::parse_from_str(value.get(..8)?, "%Y%m%d").ok()?;
+    match &value[8..] {
+        "" => Some(date),
+        time => {
+            let time = time.strip_prefix('T')?;
+            let time = time.strip_suffix('Z').unwrap_or(time);
+            (time.len() == 6 && time.bytes().all(|b| b.is_ascii_digit())).then_some(date)
+        }
+    }
+}
+
+fn parse_list<T>(
+    part: &str,
+    value: &str,
+    parse: impl Fn(&str) -> Option<T>,
+) -> Result<Vec<T>, RecurrenceError> {
+    value
+        .split(',')
+        .map(|item| parse(item).ok_or_else(|| RecurrenceError::Invalid(part.to_string())))
+        .collect()
+}
+
+impl FromStr for RecurrenceRule {
+    type Err = RecurrenceError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        let value = value.strip_prefix("RRULE:").unwrap_or(value);
+
+        let mut freq = None;
+        let mut rule = RecurrenceRule {
+            freq: Frequency::Daily,
+            interval: 1,
+            count: None,
+            until: None,
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            by_month: Vec::new(),
+            week_start: Weekday::Mon,
+        };
+
+        for part in value.split(';').filter(|part| !part.is_empty()) {
+            let invalid = || RecurrenceError::Invalid(part.to_string());
+            let (key, val) = part.split_once('=').ok_or_else(invalid)?;
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    freq = Some(match val.to_ascii_uppercase().as_str() {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        _ => return Err(RecurrenceError::Unsupported(part.to_string())),
+                    })
+                }
+                "INTERVAL" => {
+                    rule.interval = val.parse().ok().filter(|n| *n > 0).ok_or_else(invalid)?
+                }
+                "COUNT" => {
+                    rule.count = Some(val.parse().ok().filter(|n| *n > 0).ok_or_else(invalid)?)
+                }
+                "UNTIL" => rule.until = Some(parse_until(val).ok_or_else(invalid)?),
+                "BYDAY" => rule.by_day = parse_list(part, &val.to_ascii_uppercase(), parse_by_day)?,
+                "BYMONTHDAY" => {
+                    rule.by_month_day = parse_list(part, val, |item| {
+                        item.parse()
+                            .ok()
+                            .filter(|day: &i32| *day != 0 && (-31..=31).contains(day))
+                    })?
+                }
+                "BYMONTH" => {
+                    rule.by_month = parse_list(part, val, |item| {
+                        item.parse().ok().filter(|month| (1..=12).contains(month))
+                    })?
+                }
+                "WKST" => {
+                    rule.week_start =
+                        parse_weekday(&val.to_ascii_uppercase()).ok_or_else(invalid)?
+                }
+                _ => return Err(RecurrenceError::Unsupported(part.to_string())),
+            }
+        }
+
+        rule.freq = freq.ok_or(RecurrenceError::MissingFrequency)?;
+        if rule.count.is_some() && rule.until.is_some() {
+            return Err(RecurrenceError::CountWithUntil);
+        }
+        let has_ordinal = rule.by_day.iter().any(|day| day.ordinal.is_some());
+        if has_ordinal && matches!(rule.freq, Frequency::Daily | Frequency::Weekly) {
+            return Err(RecurrenceError::Invalid("BYDAY ordinal".to_string()));
+        }
+        if rule.freq == Frequency::Weekly && !rule.by_month_day.is_empty() {
+            return Err(RecurrenceError::Invalid(
+                "BYMONTHDAY with WEEKLY".to_string(),
+            ));
+        }
+        rule.by_month.sort_unstable();
+        Ok(rule)
+    }
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    NaiveDate::from_ymd_opt(next_year, next_month, 1)
+        .and_then(|first| first.pred_opt())
+        .map_or(28, |last| last.day())
+}
+
+fn month_days(year: i32, month: u32) -> Vec<NaiveDate> {
+    (1..=days_in_month(year, month))
+        .filter_map(|day| NaiveDate::from_ymd_opt(year, month, day))
+        .collect()
+}
+
+fn month_day_matches(date: NaiveDate, month_day: i32) -> bool {
+    let dim = days_in_month(date.year(), date.month()) as i32;
+    let day = date.day() as i32;
+    day == month_day || day == dim + 1 + month_day
+}
+
+impl RecurrenceRule {
+    /// Picks BYDAY matches out of `scope` (a month or a year), honoring ordinals
+    /// relative to that scope.
+    fn by_day_in(&self, scope: &[NaiveDate]) -> Vec<NaiveDate> {
+        let mut dates = Vec::new();
+        for by_day in &self.by_day {
+            let matching: Vec<_> = scope
+                .iter()
+                .copied()
+                .filter(|date| date.weekday() == by_day.weekday)
+                .collect();
+            match by_day.ordinal {
+                None => dates.extend(matching),
+                Some(n) => {
+                    let index = if n > 0 {
+                        n - 1
+                    } else {
+                        matching.len() as i32 + n
+                    };
+                    if let Some(date) = usize::try_from(index).ok().and_then(|i| matching.get(i)) {
+                        dates.push(*date);
+                    }
+                }
+            }
+        }
+        dates
+    }
+
+    fn month_candidates(&self, year: i32, month: u32, start: NaiveDate) -> Vec<NaiveDate> {
+        let days = month_days(year, month);
+        match (self.by_day.is_empty(), self.by_month_day.is_empty()) {
+            (true, true) => NaiveDate::from_ymd_opt(year, month, start.day())
+                .into_iter()
+                .collect(),
+            (true, false) => days
+                .into_iter()
+                .filter(|date| {
+                    self.by_month_day
+                        .iter()
+                        .any(|d| month_day_matches(*date, *d))
+                })
+                .collect(),
+            (false, true) => self.by_day_in(&days),
+            (false, false) => self
+                .by_day_in(&days)
+                .into_iter()
+                .filter(|date| {
+                    self.by_month_day
+                        .iter()
+                        .any(|d| month_day_matches(*date, *d))
+                })
+                .collect(),
+        }
+    }
+
+    fn period_candidates(&self, start: NaiveDate, period: i64) -> Option<Vec<NaiveDate>> {
+        let step = period * i64::from(self.interval);
+        let mut dates = match self.freq {
+            Frequency::Daily => {
+                let date = start.checked_add_signed(Duration::try_days(step)?)?;
+                let weekday_ok = self.by_day.is_empty()
+                    || self.by_day.iter().any(|d| d.weekday == date.weekday());
+                let month_day_ok = self.by_month_day.is_empty()
+                    || self
+                        .by_month_day
+                        .iter()
+                        .any(|d| month_day_matches(date, *d));
+                if weekday_ok && month_day_ok {
+                    vec![date]
+                } else {
+                    Vec::new()
+                }
+            }
+            Frequency::Weekly => {
+                let offset = start.weekday().days_since(self.week_start);
+                let week = start
+                    .checked_sub_signed(Duration::days(i64::from(offset)))?
+                    .checked_add_signed(Duration::try_weeks(step)?)?;
+                let days: Vec<_> = (0..7)
+                    .filter_map(|i| week.checked_add_signed(Duration::days(i)))
+                    .collect();
+                if self.by_day.is_empty() {
+                    days.into_iter()
+                        .filter(|date| date.weekday() == start.weekday())
+                        .collect()
+                } else {
+                    self.by_day_in(&days)
+                }
+            }
+            Frequency::Monthly => {
+                let index = i64::from(start.year()) * 12 + i64::from(start.month0()) + step;
+                let year = i32::try_from(index.div_euclid(12)).ok()?;
+                let month = u32::try_from(index.rem_euclid(12)).ok()? + 1;
+                NaiveDate::from_ymd_opt(year, 1, 1)?;
+                self.month_candidates(year, month, start)
+            }
+            Frequency::Yearly => {
+                let year = i32::try_from(i64::from(start.year()) + step).ok()?;
+                NaiveDate::from_ymd_opt(year, 1, 1)?;
+                if !self.by_month.is_empty() {
+                    self.by_month
+                        .iter()
+                        .flat_map(|month| self.month_candidates(year, *month, start))
+                        .collect()
+                } else if !self.by_day.is_empty() {
+                    // Without BYMONTH, BYDAY ordinals count within the year, so
+                    // BYMONTHDAY can only filter afterwards.
+                    let days: Vec<_> = (1..=12).flat_map(|month| month_days(year, month)).collect();
+                    self.by_day_in(&days)
+                        .into_iter()
+                        .filter(|date| {
+                            self.by_month_day.is_empty()
+                                || self
+                                    .by_month_day
+                                    .iter()
+                                    .any(|d| month_day_matches(*date, *d))
+                        })
+                        .collect()
+                } else if !self.by_month_day.is_empty() {
+                    (1..=12)
+                        .flat_map(|month| self.month_candidates(year, month, start))
+                        .collect()
+                } else {
+                    NaiveDate::from_ymd_opt(year, start.month(), start.day())
+                        .into_iter()
+                        .collect()
+                }
+            }
+        };
+        if !self.by_month.is_empty() {
+            dates.retain(|date| self.by_month.contains(&date.month()));
+        }
+        dates.sort_unstable();
+        dates.dedup();
+        Some(dates)
+    }
+
+    /// Expands occurrences on or after `start`, stopping at COUNT, UNTIL,
+    /// `limit`, or [`MAX_OCCURRENCES`], whichever comes first.
+    pub fn expand(&self, start: NaiveDate, limit: usize) -> Vec<NaiveDate> {
+        let mut limit = limit.min(MAX_OCCURRENCES);
+        if let Some(count) = self.count {
+            limit = limit.min(count as usize);
+        }
+
+        let mut occurrences = Vec::new();
+        for period in 0..MAX_PERIODS {
+            let Some(candidates) = self.period_candidates(start, period) else {
+                break;
+            };
+            for date in candidates.into_iter().filter(|date| *date >= start) {
+                if self.until.is_some_and(|until| date > until) || occurrences.len() >= limit {
+                    return occurrences;
+                }
+                occurrences.push(date);
+            }
+        }
+        occurrences
+    }
+}
+
+/// Expands an RRULE from `start` for previews and imported recurring tasks.
+#[tauri::command]
+pub fn expand_rrule(
+    rule: String,
+    start: NaiveDate,
+    limit: Option<usize>,
+) -> Result<Vec<NaiveDate>, String> {
+    let rule: RecurrenceRule = rule
+        .parse()
+        .map_err(|err: RecurrenceError| err.to_string())?;
+    Ok(rule.expand(start, limit.unwrap_or(MAX_OCCURRENCES)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(value: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap()
+    }
+
+    fn expand(rule: &str, start: &str, limit: usize) -> Vec<String> {
+        rule.parse::<RecurrenceRule>()
+            .unwrap()
+            .expand(date(start), limit)
+            .iter()
+            .map(|d| d.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn daily_count() {
+        assert_eq!(
+            expand("FREQ=DAILY;COUNT=3", "2025-01-30", 100),
+            ["2025-01-30", "2025-01-31", "2025-02-01"]
+        );
+    }
+
+    #[test]
+    fn weekly_byday_count() {
+        assert_eq!(
+            expand(
+                "RRULE:FREQ=WEEKLY;BYDAY=MO,WE,FR;COUNT=5",
+                "2025-10-08",
+                100
+            ),
+            [
+                "2025-10-08",
+                "2025-10-10",
+                "2025-10-13",
+                "2025-10-15",
+                "2025-10-17"
+            ]
+        );
+    }
+
+    #[test]
+    fn weekly_interval_until_is_inclusive() {
+        assert_eq!(
+            expand(
+                "FREQ=WEEKLY;INTERVAL=2;BYDAY=TU;UNTIL=20251104",
+                "2025-10-07",
+                100
+            ),
+            ["2025-10-07", "2025-10-21", "2025-11-04"]
+        );
+    }
+
+    #[test]
+    fn until_accepts_datetime_form() {
+        assert_eq!(
+            expand("FREQ=DAILY;UNTIL=20251010T235959Z", "2025-10-08", 100),
+            ["2025-10-08", "2025-10-09", "2025-10-10"]
+        );
+    }
+
+    #[test]
+    fn monthly_byday_ordinals() {
+        assert_eq!(
+            expand("FREQ=MONTHLY;BYDAY=-1FR;COUNT=3", "2025-10-01", 100),
+            ["2025-10-31", "2025-11-28", "2025-12-26"]
+        );
+        assert_eq!(
+            expand("FREQ=MONTHLY;BYDAY=2TU;COUNT=2", "2025-10-01", 100),
+            ["2025-10-14", "2025-11-11"]
+        );
+    }
+
+    #[test]
+    fn monthly_on_31st_skips_short_months() {
+        assert_eq!(
+            expand("FREQ=MONTHLY;COUNT=3", "2025-01-31", 100),
+            ["2025-01-31", "2025-03-31", "2025-05-31"]
+        );
+    }
+
+    #[test]
+    fn yearly_leap_day() {
+        assert_eq!(
+            expand("FREQ=YEARLY;COUNT=2", "2024-02-29", 100),
+            ["2024-02-29", "2028-02-29"]
+        );
+    }
+
+    #[test]
+    fn yearly_byday_ordinal_counts_within_the_year() {
+        assert_eq!(
+            expand(
+                "FREQ=YEARLY;BYDAY=20MO;BYMONTHDAY=13,14,15,16,17,18,19;COUNT=2",
+                "2026-01-01",
+                100
+            ),
+            ["2026-05-18", "2027-05-17"]
+        );
+        assert_eq!(
+            expand(
+                "FREQ=YEARLY;BYDAY=-1FR;BYMONTHDAY=-1;COUNT=1",
+                "2026-01-01",
+                100
+            ),
+            ["2027-12-31"]
+        );
+    }
+
+    #[test]
+    fn expansion_is_bounded() {
+        assert_eq!(expand("FREQ=DAILY", "2025-01-01", 10).len(), 10);
+        assert_eq!(
+            expand("FREQ=DAILY", "2025-01-01", usize::MAX).len(),
+            MAX_OCCURRENCES
+        );
+        assert!(expand("FREQ=MONTHLY;BYMONTH=2;BYMONTHDAY=30", "2025-01-01", 100).is_empty());
+    }
+
+    #[test]
+    fn rejects_invalid_rules() {
+        let parse = |rule: &str| rule.parse::<RecurrenceRule>();
+        assert_eq!(parse("COUNT=3"), Err(RecurrenceError::MissingFrequency));
+        assert_eq!(
+            parse("FREQ=DAILY;COUNT=3;UNTIL=20251231"),
+            Err(RecurrenceError::CountWithUntil)
+        );
+        assert!(matches!(
+            parse("FREQ=MONTHLY;BYSETPOS=-1"),
+            Err(RecurrenceError::Unsupported(_))
+        ));
+        assert!(matches!(
+            parse("FREQ=WEEKLY;BYDAY=1MO"),
+            Err(RecurrenceError::Invalid(_))
+        ));
+        assert!(matches!(
+            parse("FREQ=WEEKLY;BYDAY=XX"),
+            Err(RecurrenceError::Invalid(_))
+        ));
+        assert!(matches!(
+            parse("FREQ=DAILY;INTERVAL=0"),
+            Err(RecurrenceError::Invalid(_))
+        ));
+    }
+}
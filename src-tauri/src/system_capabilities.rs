@@ -0,0 +1,207 @@
+//! Hardware probe for local inference guidance.
+//!
+//! Reports memory, free disk, and CPU SIMD support, and maps them to the
+//! largest model size class that is practical to run with Ollama (assuming
+//! 4-bit quantization). VRAM is reported as `None` until a GPU probe exists.
+
+use std::path::Path;
+
+use serde::Serialize;
+use sysinfo::{Disks, System};
+use tauri::{AppHandle, Manager};
+
+const GIB: u64 = 1024 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModelSizeTier {
+    /// Up to ~3B parameters.
+    Tiny,
+    /// 7–8B parameters.
+    Small,
+    /// 13–14B parameters.
+    Medium,
+    /// 30–34B parameters.
+    Large,
+    /// 70B parameters and up.
+    XLarge,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SystemAiCapabilities {
+    pub total_memory_bytes: u64,
+    pub available_memory_bytes: u64,
+    pub vram_bytes: Option<u64>,
+    pub free_disk_bytes: Option<u64>,
+    pub arch: &'static str,
+    pub cpu_features: Vec<&'static str>,
+    pub logical_cores: usize,
+    pub recommended_tier: ModelSizeTier,
+}
+
+#[cfg(target_arch = "x86_64")]
+fn cpu_features() -> Vec<&'static str> {
+    let mut features = Vec::new();
+    macro_rules! detect {
+        ($($feature:tt),*) => {
+            $(if std::arch::is_x86_feature_detected!($feature) {
+                features.push($feature);
+            })*
+        };
+    }
+    detect!("sse2", "sse4.2", "avx", "avx2", "fma", "f16c", "avx512f");
+    features
+}
+
+#[cfg(target_arch = "aarch64")]
+fn cpu_features() -> Vec<&'static str> {
+    let mut features = Vec::new();
+    macro_rules! detect {
+        ($($feature:tt),*) => {
+            $(if std::arch::is_aarch64_feature_detected!($feature) {
+                features.push($feature);
+            })*
+        };
+    }
+    detect!("neon", "dotprod", "fp16");
+    features
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+fn cpu_features() -> Vec<&'static str> {
+    Vec::new()
+}
+
+/// Picks the largest practical model class. GPU memory wins when known;
+/// CPU-only machines without AVX2/NEON are capped at small models because
+/// generation is too slow beyond that.
+fn recommend_tier(memory_bytes: u64, vram_bytes: Option<u64>, features: &[&str]) -> ModelSizeTier {
+    let budget = vram_bytes.unwrap_or(memory_bytes);
+    // The OS reports somewhat less than the installed size (~15.3 GiB on a
+    // 16 GB machine), so allow 10% headroom before matching nominal sizes.
+    let nominal = budget + budget / 10;
+    let tier = match nominal / GIB {
+        0..8 => ModelSizeTier::Tiny,
+        8..16 => ModelSizeTier::Small,
+        16..32 => ModelSizeTier::Medium,
+        32..64 => ModelSizeTier::Large,
+        _ => ModelSizeTier::XLarge,
+    };
+    let fast_simd = features
+        .iter()
+        .any(|feature| matches!(*feature, "avx2" | "neon"));
+    if vram_bytes.is_none() && !fast_simd {
+        tier.min(ModelSizeTier::Small)
+    } else {
+        tier
+    }
+}
+
+/// Free space on the disk holding `path` (the mount point with the longest
+/// matching prefix).
+fn free_disk_bytes(path: &Path) -> Option<u64> {
+    Disks::new_with_refreshed_list()
+        .iter()
+        .filter(|disk| path.starts_with(disk.mount_point()))
+        .max_by_key(|disk| disk.mount_point().as_os_str().len())
+        .map(|disk| disk.available_space())
+}
+
+/// Reports RAM, free disk, CPU features, and a recommended model size class.
+#[tauri::command]
+pub async fn get_system_ai_capabilities(app: AppHandle) -> SystemAiCapabilities {
+    let mut system = System::new();
+    system.refresh_memory();
+    let total_memory_bytes = system.total_memory();
+
+    let features = cpu_features();
+    let vram_bytes = None;
+    SystemAiCapabilities {
+        total_memory_bytes,
+        available_memory_bytes: system.available_memory(),
+        vram_bytes,
+        free_disk_bytes: app
+            .path()
+            .app_data_dir()
+            .ok()
+            .and_then(|dir| free_disk_bytes(&dir)),
+        arch: std::env::consts::ARCH,
+        recommended_tier: recommend_tier(total_memory_bytes, vram_bytes, &features),
+        cpu_features: features,
+        logical_cores: std::thread::available_parallelism().map_or(1, |n| n.get()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memory_maps_to_tiers() {
+        let simd = ["avx2"];
+        assert_eq!(recommend_tier(4 * GIB, None, &simd), ModelSizeTier::Tiny);
+        assert_eq!(recommend_tier(8 * GIB, None, &simd), ModelSizeTier::Small);
+        assert_eq!(recommend_tier(16 * GIB, None, &simd), ModelSizeTier::Medium);
+        assert_eq!(recommend_tier(48 * GIB, None, &simd), ModelSizeTier::Large);
+        assert_eq!(
+            recommend_tier(128 * GIB, None, &simd),
+            ModelSizeTier::XLarge
+        );
+    }
+
+    #[test]
+    fn reported_memory_maps_to_nominal_tiers() {
+        let simd = ["avx2"];
+        let gib = |value: f64| (value * GIB as f64) as u64;
+        assert_eq!(recommend_tier(gib(6.0), None, &simd), ModelSizeTier::Tiny);
+        assert_eq!(recommend_tier(gib(7.6), None, &simd), ModelSizeTier::Small);
+        assert_eq!(
+            recommend_tier(gib(15.3), None, &simd),
+            ModelSizeTier::Medium
+        );
+        assert_eq!(recommend_tier(gib(31.2), None, &simd), ModelSizeTier::Large);
+        assert_eq!(
+            recommend_tier(gib(62.5), None, &simd),
+            ModelSizeTier::XLarge
+        );
+        assert_eq!(
+            recommend_tier(64 * GIB, Some(gib(11.6)), &[]),
+            ModelSizeTier::Small
+        );
+    }
+
+    #[test]
+    fn vram_takes_precedence_over_ram() {
+        assert_eq!(
+            recommend_tier(64 * GIB, Some(12 * GIB), &[]),
+            ModelSizeTier::Small
+        );
+        assert_eq!(
+            recommend_tier(8 * GIB, Some(24 * GIB), &[]),
+            ModelSizeTier::Medium
+        );
+    }
+
+    #[test]
+    fn cpu_only_without_fast_simd_is_capped() {
+        assert_eq!(
+            recommend_tier(64 * GIB, None, &["sse2", "avx"]),
+            ModelSizeTier::Small
+        );
+        assert_eq!(
+            recommend_tier(64 * GIB, None, &["neon"]),
+            ModelSizeTier::XLarge
+        );
+        assert_eq!(recommend_tier(4 * GIB, None, &[]), ModelSizeTier::Tiny);
+    }
+
+    #[test]
+    fn detects_baseline_features() {
+        let features = cpu_features();
+        if cfg!(target_arch = "x86_64") {
+            assert!(features.contains(&"sse2"));
+        } else if cfg!(target_arch = "aarch64") {
+            assert!(features.contains(&"neon"));
+        }
+    }
+}
@@ -0,0 +1,223 @@
+//! Typed task time blocks.
+//!
+//! Time blocks travel in task notes metadata as ISO 8601 intervals:
+//! `2025-10-08T09:00:00Z/2025-10-08T10:30:00Z` for timed blocks and
+//! `2025-10-08/2025-10-09` (end exclusive) for all-day blocks.
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, NaiveDate, NaiveTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "RawTimeBlock")]
+pub struct TimeBlock {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub all_day: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeBlockError {
+    Malformed(String),
+    EndNotAfterStart,
+    AllDayNotMidnight,
+}
+
+impl fmt::Display for TimeBlockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Malformed(value) => write!(f, "malformed time block: {value:?}"),
+            Self::EndNotAfterStart => f.write_str("time block must end after it starts"),
+            Self::AllDayNotMidnight => {
+                f.write_str("all-day time blocks must start and end at midnight UTC")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TimeBlockError {}
+
+impl TimeBlock {
+    pub fn new(
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        all_day: bool,
+    ) -> Result<Self, TimeBlockError> {
+        if end <= start {
+            return Err(TimeBlockError::EndNotAfterStart);
+        }
+        if all_day && (start.time() != NaiveTime::MIN || end.time() != NaiveTime::MIN) {
+            return Err(TimeBlockError::AllDayNotMidnight);
+        }
+        Ok(Self {
+            start,
+            end,
+            all_day,
+        })
+    }
+}
+
+impl fmt::Display for TimeBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.all_day {
+            write!(
+                f,
+                "{}/{}",
+                self.start.format(DATE_FORMAT),
+                self.end.format(DATE_FORMAT)
+            )
+        } else {
+            write!(
+                f,
+                "{}/{}",
+                self.start.to_rfc3339_opts(SecondsFormat::Secs, true),
+                self.end.to_rfc3339_opts(SecondsFormat::Secs, true)
+            )
+        }
+    }
+}
+
+impl FromStr for TimeBlock {
+    type Err = TimeBlockError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let malformed = || TimeBlockError::Malformed(value.to_string());
+        let (start, end) = value.trim().split_once('/').ok_or_else(malformed)?;
+
+        if let (Ok(start), Ok(end)) = (
+            NaiveDate::parse_from_str(start, DATE_FORMAT),
+            NaiveDate::parse_from_str(end, DATE_FORMAT),
+        ) {
+            return Self::new(
+                start.and_time(NaiveTime::MIN).and_utc(),
+                end.and_time(NaiveTime::MIN).and_utc(),
+                true,
+            );
+        }
+
+        let start = DateTime::parse_from_rfc3339(start).map_err(|_| malformed())?;
+        let end = DateTime::parse_from_rfc3339(end).map_err(|_| malformed())?;
+        Self::new(start.with_timezone(&Utc), end.with_timezone(&Utc), false)
+    }
+}
+
+#[derive(Deserialize)]
+struct RawTimeBlock {
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    #[serde(default)]
+    all_day: bool,
+}
+
+impl TryFrom<RawTimeBlock> for TimeBlock {
+    type Error = TimeBlockError;
+
+    fn try_from(raw: RawTimeBlock) -> Result<Self, Self::Error> {
+        Self::new(raw.start, raw.end, raw.all_day)
+    }
+}
+
+/// Parses a notes-metadata time block string into its typed form.
+#[tauri::command]
+pub fn parse_time_block(value: String) -> Result<TimeBlock, String> {
+    value.parse().map_err(|err: TimeBlockError| err.to_string())
+}
+
+/// Renders a typed time block back into its notes-metadata string.
+#[tauri::command]
+pub fn format_time_block(block: TimeBlock) -> String {
+    block.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn timed_block_round_trips() {
+        let block: TimeBlock = "2025-10-08T09:00:00Z/2025-10-08T10:30:00Z".parse().unwrap();
+        assert!(!block.all_day);
+        assert_eq!(
+            block.start,
+            Utc.with_ymd_and_hms(2025, 10, 8, 9, 0, 0).unwrap()
+        );
+        assert_eq!(
+            block.to_string(),
+            "2025-10-08T09:00:00Z/2025-10-08T10:30:00Z"
+        );
+    }
+
+    #[test]
+    fn offsets_are_normalized_to_utc() {
+        let block: TimeBlock = "2025-10-08T09:00:00+02:00/2025-10-08T10:00:00+02:00"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            block.to_string(),
+            "2025-10-08T07:00:00Z/2025-10-08T08:00:00Z"
+        );
+    }
+
+    #[test]
+    fn all_day_block_round_trips() {
+        let block: TimeBlock = "2025-10-08/2025-10-09".parse().unwrap();
+        assert!(block.all_day);
+        assert_eq!(block.to_string(), "2025-10-08/2025-10-09");
+    }
+
+    #[test]
+    fn rejects_end_not_after_start() {
+        assert_eq!(
+            "2025-10-08T10:00:00Z/2025-10-08T09:00:00Z".parse::<TimeBlock>(),
+            Err(TimeBlockError::EndNotAfterStart)
+        );
+        assert_eq!(
+            "2025-10-08/2025-10-08".parse::<TimeBlock>(),
+            Err(TimeBlockError::EndNotAfterStart)
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_values() {
+        for value in [
+            "",
+            "9am-10am",
+            "2025-10-08/2025-10-08T10:00:00Z",
+            "tomorrow/later",
+        ] {
+            assert!(matches!(
+                value.parse::<TimeBlock>(),
+                Err(TimeBlockError::Malformed(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn all_day_requires_midnight_bounds() {
+        let start = Utc.with_ymd_and_hms(2025, 10, 8, 9, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 10, 9, 0, 0, 0).unwrap();
+        assert_eq!(
+            TimeBlock::new(start, end, true),
+            Err(TimeBlockError::AllDayNotMidnight)
+        );
+    }
+
+    #[test]
+    fn deserialization_validates() {
+        let ok: TimeBlock = serde_json::from_str(
+            r#"{"start":"2025-10-08T09:00:00Z","end":"2025-10-08T10:00:00Z"}"#,
+        )
+        .unwrap();
+        assert!(!ok.all_day);
+
+        let err = serde_json::from_str::<TimeBlock>(
+            r#"{"start":"2025-10-08T10:00:00Z","end":"2025-10-08T09:00:00Z","all_day":false}"#,
+        );
+        assert!(err.is_err());
+    }
+}